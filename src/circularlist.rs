@@ -1,99 +1,229 @@
-use std::cell::RefCell;
+use crate::ring::Ring;
+use std::collections::vec_deque::Iter;
 use std::fmt::Debug;
-use std::rc::Rc;
-
-#[derive(Clone, Debug)]
-pub struct Node<T: Clone> {
-    pub value: T,
-    pub next: Option<Rc<RefCell<Node<T>>>>,
-    pub prev: Option<Rc<RefCell<Node<T>>>>,
-}
 
+/// Compatibility wrapper around [`Ring`] that keeps the original
+/// `CircularList` API, including its by-value accessors.
 #[derive(Clone, Debug)]
 pub struct CircularList<T: Clone> {
-    pub head: Option<Rc<RefCell<Node<T>>>>,
-    pub size: usize,
+    ring: Ring<T>,
 }
 
 impl<T: Clone + Debug> CircularList<T> {
     pub fn new() -> Self {
-        CircularList {
-            head: None,
-            size: 0,
-        }
+        CircularList { ring: Ring::new() }
     }
 
+    /// Keeps the placement of the original linked list: the value goes right
+    /// after the element `index + 1` steps past the head, and index 0 also
+    /// makes it the new head.
     pub fn insert(&mut self, value: T, index: usize) {
-        let new_node = Rc::new(RefCell::new(Node {
-            value,
-            next: None,
-            prev: None,
-        }));
-
-        if self.head.is_none() {
-            new_node.borrow_mut().next = Some(Rc::clone(&new_node));
-            new_node.borrow_mut().prev = Some(Rc::clone(&new_node));
-            self.head = Some(new_node);
-        } else {
-            let mut current = self.head.as_ref().unwrap().borrow().next.clone();
-            for _ in 0..index {
-                let next = current.as_ref().unwrap().borrow().next.clone();
-                current = next;
-            }
-
-            let next = current.as_ref().unwrap().borrow().next.clone();
-
-            new_node.borrow_mut().next = next.clone();
-            new_node.borrow_mut().prev = current.clone();
-
-            current.as_ref().unwrap().borrow_mut().next = Some(Rc::clone(&new_node));
-            next.as_ref().unwrap().borrow_mut().prev = Some(Rc::clone(&new_node));
-
-            if index == 0 {
-                self.head = Some(Rc::clone(&new_node));
-            }
+        let len = self.ring.len();
+        if len == 0 {
+            self.ring.push_back(value);
+            return;
         }
 
-        self.size += 1;
+        let position = (index + 1) % len + 1;
+        self.ring.insert(position, value);
+
+        if index == 0 {
+            self.ring.rotate_left(position);
+        }
     }
 
     pub fn len(&self) -> usize {
-        self.size
+        self.ring.len()
     }
-}
 
-pub struct CircularListIterator<T: Clone> {
-    current: Option<Rc<RefCell<Node<T>>>>,
-    end: Option<Rc<RefCell<Node<T>>>>,
-    first: bool,
-}
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
 
-impl<T: Clone + Debug> Iterator for CircularListIterator<T> {
-    type Item = T;
+    /// Removes the element at `index`, counted from the head in iteration
+    /// order and wrapping around the ring. Returns `None` if the list is empty.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.ring.remove(index)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let current = self.current.as_ref()?;
+    /// Like `remove`, but panics if the list is empty.
+    pub fn pop_at(&mut self, index: usize) -> T {
+        self.remove(index)
+            .expect("pop_at called on an empty CircularList")
+    }
+
+    /// Returns a clone of the element at `index`, wrapping like `remove`.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.ring.get(index).cloned()
+    }
+
+    /// Runs `f` on the element at `index` in place. Use `as_ring_mut` to get a
+    /// plain `&mut T` instead.
+    pub fn get_mut<R>(&mut self, index: usize, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.ring.get_mut(index).map(f)
+    }
+
+    /// Moves the head forward so the element at `n` becomes index 0.
+    pub fn rotate_left(&mut self, n: usize) {
+        self.ring.rotate_left(n);
+    }
+
+    /// Moves the head backward so the element at index 0 ends up at `n`.
+    pub fn rotate_right(&mut self, n: usize) {
+        self.ring.rotate_right(n);
+    }
+
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.ring.position(predicate)
+    }
+
+    pub fn clear(&mut self) {
+        self.ring.clear();
+    }
+
+    pub fn from_vec(values: Vec<T>) -> Self {
+        CircularList {
+            ring: Ring::from(values),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    pub fn as_ring(&self) -> &Ring<T> {
+        &self.ring
+    }
+
+    pub fn as_ring_mut(&mut self) -> &mut Ring<T> {
+        &mut self.ring
+    }
+}
 
-        if !self.first && Rc::ptr_eq(current, self.end.as_ref().unwrap()) {
-            return None;
+impl<T: Clone + Debug> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Debug> FromIterator<T> for CircularList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        CircularList {
+            ring: iter.into_iter().collect(),
         }
+    }
+}
 
-        let value = current.borrow().value.clone();
-        let next = current.borrow().next.clone();
+impl<T: Clone + Debug> Extend<T> for CircularList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.ring.extend(iter);
+    }
+}
 
-        self.current = next;
-        self.first = false;
+pub struct CircularListIterator<'a, T: Clone> {
+    inner: Iter<'a, T>,
+}
 
-        Some(value)
+impl<'a, T: Clone + Debug> Iterator for CircularListIterator<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().cloned()
     }
 }
 
 impl<T: Clone + Debug> CircularList<T> {
-    pub fn iter(&self) -> CircularListIterator<T> {
+    pub fn iter(&self) -> CircularListIterator<'_, T> {
         CircularListIterator {
-            current: self.head.clone(),
-            end: self.head.clone(),
-            first: true,
+            inner: self.ring.iter(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_wraps_past_len() {
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.remove(3), Some(1));
+        assert_eq!(list.to_vec(), vec![2, 3]);
+
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.remove(3 + 2), Some(3));
+        assert_eq!(list.to_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn remove_head_moves_head_forward() {
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.remove(0), Some(1));
+        assert_eq!(list.get(0), Some(2));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn remove_on_empty_returns_none() {
+        let mut list: CircularList<i32> = CircularList::new();
+        assert_eq!(list.remove(0), None);
+        assert_eq!(list.remove(5), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pop_at_on_empty_panics() {
+        let mut list: CircularList<i32> = CircularList::new();
+        list.pop_at(0);
+    }
+
+    #[test]
+    fn insert_after_clear() {
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        list.clear();
+        assert!(list.is_empty());
+
+        list.insert(4, 2);
+        assert_eq!(list.to_vec(), vec![4]);
+    }
+
+    #[test]
+    fn insert_index_zero_becomes_head() {
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        list.insert(9, 0);
+        assert_eq!(list.to_vec(), vec![9, 3, 1, 2]);
+    }
+
+    #[test]
+    fn insert_into_single_element_list() {
+        let mut list = CircularList::from_vec(vec![1]);
+        list.insert(9, 0);
+        assert_eq!(list.to_vec(), vec![9, 1]);
+
+        let mut list = CircularList::from_vec(vec![1]);
+        list.insert(9, 3);
+        assert_eq!(list.to_vec(), vec![1, 9]);
+    }
+
+    #[test]
+    fn insert_goes_after_index_plus_one() {
+        let mut list = CircularList::from_vec(vec![1, 2, 3, 4]);
+        list.insert(9, 1);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 9, 4]);
+
+        let mut list = CircularList::from_vec(vec![1, 2, 3, 4]);
+        list.insert(9, 2);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 9]);
+    }
+
+    #[test]
+    fn insert_wraps_past_len() {
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        list.insert(9, 3);
+        assert_eq!(list.to_vec(), vec![1, 2, 9, 3]);
+
+        let mut list = CircularList::from_vec(vec![1, 2, 3]);
+        list.insert(9, 7);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 9]);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::gamestate::GameState;
+use image::{Rgba, RgbaImage};
+use std::f32::consts::PI;
+
+/// Renders the parsed game state as a schematic: ring atoms as discs in their
+/// element colours, with the player atom in the centre. `GameState` doesn't
+/// keep the detected slot angles, so atoms are spaced evenly in ring order,
+/// starting on the left and running clockwise.
+///
+/// Element symbols aren't drawn yet because the crate has no font to render
+/// text with.
+pub fn render_ring_diagram(game_state: &GameState, size: u32) -> RgbaImage {
+    let mut image = RgbaImage::from_pixel(size, size, Rgba([0, 0, 0, 255]));
+
+    let center = size as f32 / 2.0;
+    let ring_radius = size as f32 * 0.38;
+    let atom_radius = size as f32 * 0.07;
+
+    let count = game_state.ring.len();
+    for (i, element) in game_state.ring.iter().enumerate() {
+        let angle = PI + i as f32 / count as f32 * 2.0 * PI;
+        let x = center + ring_radius * angle.cos();
+        let y = center + ring_radius * angle.sin();
+        draw_disc(&mut image, x, y, atom_radius, element.rgb);
+    }
+
+    draw_disc(
+        &mut image,
+        center,
+        center,
+        atom_radius * 1.3,
+        game_state.player_atom.rgb,
+    );
+
+    image
+}
+
+fn draw_disc(image: &mut RgbaImage, cx: f32, cy: f32, radius: f32, rgb: (u8, u8, u8)) {
+    let min_x = (cx - radius).floor().max(0.0) as u32;
+    let min_y = (cy - radius).floor().max(0.0) as u32;
+    let max_x = ((cx + radius).ceil() as u32).min(image.width());
+    let max_y = ((cy + radius).ceil() as u32).min(image.height());
+
+    for x in min_x..max_x {
+        for y in min_y..max_y {
+            let dx = x as f32 + 0.5 - cx;
+            let dy = y as f32 + 0.5 - cy;
+
+            if dx * dx + dy * dy <= radius * radius {
+                image.put_pixel(x, y, Rgba([rgb.0, rgb.1, rgb.2, 255]));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circularlist::CircularList;
+    use crate::elements::{Element, Id};
+
+    fn element(rgb: (u8, u8, u8)) -> Element<'static> {
+        Element {
+            id: Id::Single('H'),
+            name: "Hydrogen",
+            rgb,
+        }
+    }
+
+    fn game_state(ring: Vec<Element<'static>>) -> GameState<'static> {
+        GameState {
+            ring: CircularList::from_vec(ring),
+            player_atom: element((10, 20, 30)),
+            max_value: 1,
+            score: 0,
+        }
+    }
+
+    #[test]
+    fn player_atom_is_drawn_in_the_centre() {
+        let state = game_state(vec![element((200, 0, 0)), element((0, 200, 0))]);
+        let image = render_ring_diagram(&state, 200);
+
+        assert_eq!(image.get_pixel(100, 100), &Rgba([10, 20, 30, 255]));
+    }
+
+    #[test]
+    fn first_ring_atom_is_drawn_on_the_left() {
+        let state = game_state(vec![element((200, 0, 0)), element((0, 200, 0))]);
+        let image = render_ring_diagram(&state, 200);
+
+        assert_eq!(image.get_pixel(100 - 76, 100), &Rgba([200, 0, 0, 255]));
+        assert_eq!(image.get_pixel(100 + 76, 100), &Rgba([0, 200, 0, 255]));
+    }
+
+    #[test]
+    fn empty_ring_and_zero_size_do_not_panic() {
+        let state = game_state(Vec::new());
+
+        assert_eq!(render_ring_diagram(&state, 50).dimensions(), (50, 50));
+        assert_eq!(render_ring_diagram(&state, 0).dimensions(), (0, 0));
+    }
+}
@@ -43,3 +43,37 @@ impl<'a> fmt::Debug for GameState<'a> {
         write!(f, "}}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::Id;
+
+    fn element(symbol: char) -> Element<'static> {
+        Element {
+            id: Id::Single(symbol),
+            name: "Test",
+            rgb: (0, 0, 0),
+        }
+    }
+
+    #[test]
+    fn game_state_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<GameState<'static>>();
+    }
+
+    #[test]
+    fn clones_do_not_share_the_ring() {
+        let mut original = GameState::new(&[element('H')]);
+        original.ring.extend([element('H'), element('O')]);
+
+        let mut clone = original.clone();
+        clone.ring.remove(0);
+        clone.ring.get_mut(0, |atom| atom.id = Id::Single('N'));
+
+        assert_eq!(original.ring.len(), 2);
+        assert_eq!(original.ring.get(1).unwrap().id, Id::Single('O'));
+        assert_eq!(clone.ring.to_vec(), vec![element('N')]);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod circularlist;
+pub mod diagram;
+pub mod elements;
+pub mod gamestate;
+pub mod parser;
+pub mod ring;
@@ -1,14 +1,34 @@
-mod circularlist;
-mod elements;
-mod gamestate;
-mod parser;
+use atomas::{elements, parser};
+use image::ImageError;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+fn main() -> Result<(), ImageError> {
+    // Verbosity comes from RUST_LOG; closing spans report how long each stage took
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
 
-fn main() {
     let path = "C:/Obsidian/Rust/atomas/assets/txt/elements.txt";
     let data = elements::Data::load(path);
 
     let board_image_path = "C:/Obsidian/Rust/atomas/assets/jpg/board.jpg";
-    let game_state = parser::detect_game_state(&board_image_path, &data);
+    let detection = parser::detect_game_state(board_image_path, &data)?;
+
+    // Save the output image for visualization
+    detection
+        .overlay
+        .save("C:/Obsidian/Rust/atomas/assets/png/outputs/detected_state.png")?;
+
+    // Save a schematic of the parsed state next to the detection overlay
+    detection
+        .ring_diagram(600)
+        .save("C:/Obsidian/Rust/atomas/assets/png/outputs/ring_diagram.png")?;
+
+    println!("Detected Game State: {:?}", detection.game_state);
 
-    println!("Detected Game State: {:?}", game_state);
+    Ok(())
 }
@@ -1,15 +1,59 @@
 use crate::circularlist::CircularList;
+use crate::diagram::render_ring_diagram;
 use crate::elements::{Data, Element};
 use crate::gamestate::GameState;
-use image::{ImageBuffer, Luma, Rgba, RgbaImage};
+use image::buffer::ConvertBuffer;
+use image::{ImageBuffer, ImageError, Luma, RgbImage, Rgba, RgbaImage};
 use template_matching::{find_extremes, Image, MatchTemplateMethod, TemplateMatcher};
+use tracing::{debug, debug_span, info, info_span, warn};
 
-pub fn detect_game_state<'a>(input_image_path: &str, data: &Data<'a>) -> GameState<'a> {
-    let input_image = image::open(input_image_path).unwrap().to_luma32f();
+/// A parsed game state together with the overlay of matched template positions.
+pub struct Detection<'a> {
+    pub game_state: GameState<'a>,
+    pub overlay: RgbaImage,
+}
+
+impl<'a> Detection<'a> {
+    /// The overlay without its alpha channel, for GUI and server callers that
+    /// work with RGB buffers.
+    pub fn overlay_rgb(&self) -> RgbImage {
+        self.overlay.convert()
+    }
+
+    /// Renders the ring diagram of the detected state in memory.
+    pub fn ring_diagram(&self, size: u32) -> RgbaImage {
+        render_ring_diagram(&self.game_state, size)
+    }
+}
+
+pub fn detect_game_state<'a>(
+    input_image_path: &str,
+    data: &Data<'a>,
+) -> Result<Detection<'a>, ImageError> {
+    let bytes = std::fs::read(input_image_path).map_err(ImageError::IoError)?;
+    detect_game_state_from_bytes(&bytes, data)
+}
+
+/// Detects the game state from an encoded PNG/JPEG buffer, e.g. the output of
+/// `adb exec-out screencap -p`. The screenshot never touches the filesystem;
+/// only the element templates are read from disk. Truncated or corrupt buffers
+/// are returned as an error.
+pub fn detect_game_state_from_bytes<'a>(
+    bytes: &[u8],
+    data: &Data<'a>,
+) -> Result<Detection<'a>, ImageError> {
+    let _span = info_span!("detect_game_state").entered();
+
+    let input_image = image::load_from_memory(bytes)?.to_luma32f();
+    debug!(
+        width = input_image.width(),
+        height = input_image.height(),
+        "decoded input image"
+    );
     let mut ring = CircularList::new();
     let mut player_atom: Option<&Element<'a>> = None;
     let mut max_value = 1;
-    let mut score = 0;
+    let score = 0;
 
     // Initialize the output image for visualization
     let mut output = RgbaImage::from_pixel(
@@ -19,10 +63,12 @@ pub fn detect_game_state<'a>(input_image_path: &str, data: &Data<'a>) -> GameSta
     );
 
     for element in &data.elements {
+        let _span = debug_span!("match_template", element = element.name).entered();
+
         let template_image = match load_template_for_element(element) {
             Some(img) => img,
             None => {
-                eprintln!("Missing template for element: {}", element.name);
+                debug!("no template, skipping element");
                 continue;
             }
         };
@@ -48,6 +94,7 @@ pub fn detect_game_state<'a>(input_image_path: &str, data: &Data<'a>) -> GameSta
         let extremes = find_extremes(&result);
 
         let (x, y) = extremes.min_value_location;
+        debug!(x, y, score = extremes.min_value, "best match");
         draw_rectangle(&mut output, x, y, 180, 180, element.rgb);
 
         // Determine if this is the player atom or part of the ring
@@ -62,20 +109,26 @@ pub fn detect_game_state<'a>(input_image_path: &str, data: &Data<'a>) -> GameSta
         max_value = max_value.max(element_to_value(element));
     }
 
-    // Save the output image for visualization
-    output
-        .save("C:/Obsidian/Rust/atomas/assets/png/outputs/detected_state.png")
-        .unwrap();
-
-    // Create and return the GameState
-    GameState {
+    // Create the GameState
+    let game_state = GameState {
         ring,
         player_atom: player_atom
             .cloned()
             .unwrap_or_else(|| data.elements[0].clone()),
         max_value,
         score,
-    }
+    };
+
+    info!(
+        ring_size = game_state.ring.len(),
+        player_atom = game_state.player_atom.name,
+        "detected game state"
+    );
+
+    Ok(Detection {
+        game_state,
+        overlay: output,
+    })
 }
 fn load_template_for_element<'a>(
     element: &Element<'a>,
@@ -85,7 +138,7 @@ fn load_template_for_element<'a>(
     match image::open(&path) {
         Ok(img) => Some(img.to_luma32f()),
         Err(err) => {
-            eprintln!("Unable to load image for {}: {}", element.name, err);
+            warn!(path, %err, "unable to load template image");
             None
         }
     }
@@ -132,3 +185,45 @@ fn calculate_ring_index(x: u32, y: u32, width: u32, height: u32) -> usize {
 fn element_to_value(element: &Element) -> i32 {
     element.name.chars().next().map(|c| c as i32).unwrap_or(0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::Id;
+
+    #[test]
+    fn overlay_rgb_drops_alpha() {
+        let player_atom = Element {
+            id: Id::Single('H'),
+            name: "Hydrogen",
+            rgb: (99, 185, 213),
+        };
+        let detection = Detection {
+            game_state: GameState {
+                ring: CircularList::new(),
+                player_atom,
+                max_value: 1,
+                score: 0,
+            },
+            overlay: RgbaImage::from_pixel(3, 2, Rgba([1, 2, 3, 255])),
+        };
+
+        let rgb = detection.overlay_rgb();
+        assert_eq!(rgb.dimensions(), (3, 2));
+        assert_eq!(rgb.get_pixel(2, 1), &image::Rgb([1, 2, 3]));
+        assert_eq!(
+            detection.ring_diagram(40).get_pixel(20, 20),
+            &Rgba([99, 185, 213, 255])
+        );
+    }
+
+    #[test]
+    fn corrupt_bytes_return_an_error() {
+        let data = Data {
+            elements: Vec::new(),
+        };
+
+        assert!(detect_game_state_from_bytes(b"\x89PNG\r\n\x1a\n", &data).is_err());
+        assert!(detect_game_state_from_bytes(b"not an image", &data).is_err());
+    }
+}
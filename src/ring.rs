@@ -0,0 +1,191 @@
+use std::collections::vec_deque::{IntoIter, Iter, IterMut};
+use std::collections::VecDeque;
+
+/// A ring of values stored contiguously in a `VecDeque`.
+///
+/// Index 0 is the current head. Every index wraps modulo the length, so
+/// `len()` refers back to the head and neighbour lookups are O(1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ring<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Ring<T> {
+    pub fn new() -> Self {
+        Ring {
+            items: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let index = self.wrap(index)?;
+        self.items.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let index = self.wrap(index)?;
+        self.items.get_mut(index)
+    }
+
+    /// Returns the elements before and after `index`. In a ring of one, both
+    /// are the element itself.
+    pub fn neighbors(&self, index: usize) -> Option<(&T, &T)> {
+        let index = self.wrap(index)?;
+        let len = self.items.len();
+
+        Some((
+            &self.items[(index + len - 1) % len],
+            &self.items[(index + 1) % len],
+        ))
+    }
+
+    /// Inserts `value` so that it ends up at `index`, wrapping over the
+    /// length after insertion.
+    pub fn insert(&mut self, index: usize, value: T) {
+        let index = index % (self.items.len() + 1);
+        self.items.insert(index, value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.items.push_back(value);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let index = self.wrap(index)?;
+        self.items.remove(index)
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Moves the head forward so the element at `n` becomes index 0.
+    pub fn rotate_left(&mut self, n: usize) {
+        if let Some(n) = self.wrap(n) {
+            self.items.rotate_left(n);
+        }
+    }
+
+    /// Moves the head backward so the element at index 0 ends up at `n`.
+    pub fn rotate_right(&mut self, n: usize) {
+        if let Some(n) = self.wrap(n) {
+            self.items.rotate_right(n);
+        }
+    }
+
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.items.iter().position(predicate)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.items.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.items.iter_mut()
+    }
+
+    fn wrap(&self, index: usize) -> Option<usize> {
+        match self.items.len() {
+            0 => None,
+            len => Some(index % len),
+        }
+    }
+}
+
+impl<T> Default for Ring<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for Ring<T> {
+    fn from(values: Vec<T>) -> Self {
+        Ring {
+            items: values.into(),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Ring<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Ring {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for Ring<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Ring<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_wraps_over_new_len() {
+        let mut ring = Ring::from(vec![1, 2, 3]);
+        ring.insert(3, 4);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        ring.insert(6, 5);
+        assert_eq!(
+            ring.iter().copied().collect::<Vec<_>>(),
+            vec![1, 5, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn neighbors_on_small_rings() {
+        let ring = Ring::from(vec![7]);
+        assert_eq!(ring.neighbors(0), Some((&7, &7)));
+
+        let ring = Ring::from(vec![1, 2]);
+        assert_eq!(ring.neighbors(0), Some((&2, &2)));
+        assert_eq!(ring.neighbors(3), Some((&1, &1)));
+    }
+
+    #[test]
+    fn rotation_wraps_past_len() {
+        let mut ring = Ring::from(vec![1, 2, 3]);
+        ring.rotate_left(4);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![2, 3, 1]);
+
+        ring.rotate_right(3);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![2, 3, 1]);
+
+        ring.rotate_right(5);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn empty_ring_has_no_elements() {
+        let mut ring: Ring<i32> = Ring::new();
+        assert_eq!(ring.get(0), None);
+        assert_eq!(ring.remove(0), None);
+        assert_eq!(ring.neighbors(0), None);
+
+        ring.rotate_left(2);
+        assert!(ring.is_empty());
+    }
+}